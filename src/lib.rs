@@ -5,8 +5,33 @@
 
 use core::cell::UnsafeCell;
 use core::mem::MaybeUninit;
+use core::ops::Deref;
 
-use portable_atomic::{AtomicBool, Ordering};
+use portable_atomic::{AtomicBool, AtomicU8, Ordering};
+
+/// Marks `state` as `poisoned` if dropped while unwinding, i.e. if the closure that was supposed
+/// to finish initializing it panicked instead. Call [`PoisonOnUnwind::defuse()`] once the value
+/// has been written so a normal return doesn't poison the cell.
+///
+/// Shared by [`OnceStaticCell`]'s and [`LazyStaticCell`]'s init state machines, which otherwise
+/// both need this exact guard.
+struct PoisonOnUnwind<'a> {
+    state: &'a AtomicU8,
+    poisoned: u8,
+}
+
+impl PoisonOnUnwind<'_> {
+    #[inline]
+    fn defuse(self) {
+        core::mem::forget(self);
+    }
+}
+
+impl Drop for PoisonOnUnwind<'_> {
+    fn drop(&mut self) {
+        self.state.store(self.poisoned, Ordering::Release);
+    }
+}
 
 /// Statically allocated, initialized at runtime cell.
 ///
@@ -120,6 +145,7 @@ impl<T> StaticCell<T> {
     /// Using this method directly is not recommended, but it can be used to construct `T` in-place directly
     /// in a guaranteed fashion.
     #[inline]
+    #[allow(clippy::mut_from_ref)]
     pub fn try_uninit(&'static self) -> Option<&'static mut MaybeUninit<T>> {
         if self
             .used
@@ -133,6 +159,41 @@ impl<T> StaticCell<T> {
             None
         }
     }
+
+    /// Reset the `StaticCell` back to "empty", reclaiming its storage for another [`StaticCell::init()`].
+    ///
+    /// The caller must pass back the exact `&'static mut T` reference they received from
+    /// [`StaticCell::init()`] or one of its siblings. Possessing that exclusive reference
+    /// proves no aliases to the value exist anywhere else, so it's sound to move the value out
+    /// and mark the cell empty again.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `reclaimed` does not point at this `StaticCell`'s storage. This check runs in
+    /// all build profiles, including release: accepting an unrelated reference here would let a
+    /// later `init()` hand out a second `&'static mut T` aliasing the one the caller still holds,
+    /// from entirely safe code.
+    #[inline]
+    pub fn reset(&'static self, reclaimed: &'static mut T) -> T {
+        assert_eq!(
+            reclaimed as *mut T as *const (),
+            self.val.get() as *const (),
+            "`StaticCell::reset()` called with a reference from a different `StaticCell`"
+        );
+        // SAFETY: `reclaimed` is the unique, exclusive reference to this cell's value, so it's
+        // sound to move it out. The cell is still marked `used`, so nothing else can observe or
+        // create another reference to this memory until the `store(false)` below.
+        let val = unsafe { core::ptr::read(reclaimed) };
+        self.used.store(false, Ordering::Release);
+        val
+    }
+}
+
+impl<T> Default for StaticCell<T> {
+    #[inline]
+    fn default() -> Self {
+        Self::new()
+    }
 }
 
 // ---
@@ -202,6 +263,273 @@ impl<T> ConstStaticCell<T> {
             None
         }
     }
+
+    /// Reset the `ConstStaticCell` back to "untaken", reclaiming it for another [`ConstStaticCell::take()`].
+    ///
+    /// The caller must pass back the exact `&'static mut T` reference they received from
+    /// [`ConstStaticCell::take()`]. Possessing that exclusive reference proves no aliases to
+    /// the value exist anywhere else, so it's sound to mark the cell untaken again. Unlike
+    /// [`StaticCell::reset()`], the value itself isn't moved out: it's left in place (still
+    /// holding whatever the previous taker wrote into it) ready for the next `take()`.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `reclaimed` does not point at this `ConstStaticCell`'s storage. This check runs
+    /// in all build profiles, including release: accepting an unrelated reference here would let
+    /// a later `take()` hand out a second `&'static mut T` aliasing the one the caller still
+    /// holds, from entirely safe code.
+    #[inline]
+    pub fn untake(&'static self, reclaimed: &'static mut T) {
+        assert_eq!(
+            reclaimed as *mut T as *const (),
+            self.val.get() as *const (),
+            "`ConstStaticCell::untake()` called with a reference from a different `ConstStaticCell`"
+        );
+        self.taken.store(false, Ordering::Release);
+    }
+}
+
+// ---
+
+const ONCE_EMPTY: u8 = 0;
+const ONCE_INITIALIZING: u8 = 1;
+const ONCE_SET: u8 = 2;
+const ONCE_POISONED: u8 = 3;
+
+/// Statically allocated cell that can be set once, and read any number of times afterwards.
+///
+/// It has two states: "empty" and "full". It is created "empty", and can be set at runtime
+/// with [`OnceStaticCell::set()`] or [`OnceStaticCell::get_or_init()`]. Once set, it stays
+/// "full" forever, and the contained value can be read by shared reference as many times as
+/// needed with [`OnceStaticCell::get()`].
+///
+/// Unlike [`StaticCell`], which only ever hands out a single `&'static mut T`, `OnceStaticCell`
+/// is meant for global singletons (loggers, config, driver handles, ...) that many call sites
+/// need to read concurrently.
+///
+/// See the [crate-level docs](crate) for usage.
+pub struct OnceStaticCell<T> {
+    state: AtomicU8,
+    val: UnsafeCell<MaybeUninit<T>>,
+}
+
+unsafe impl<T: Send> Send for OnceStaticCell<T> {}
+unsafe impl<T: Send + Sync> Sync for OnceStaticCell<T> {}
+
+impl<T> OnceStaticCell<T> {
+    /// Create a new, empty `OnceStaticCell`.
+    ///
+    /// It can be initialized at runtime with [`OnceStaticCell::set()`] or similar methods.
+    #[inline]
+    pub const fn new() -> Self {
+        Self {
+            state: AtomicU8::new(ONCE_EMPTY),
+            val: UnsafeCell::new(MaybeUninit::uninit()),
+        }
+    }
+
+    /// Claim the right to initialize the cell and run `f`, storing its result.
+    ///
+    /// Returns `true` if this call won the race and ran `f`. The state only flips to `SET`
+    /// once `f`'s return value has been written, via a `Release` store, so a racing
+    /// [`OnceStaticCell::get()`] can never observe `SET` before the value is actually there. If
+    /// `f` panics, the cell is left `POISONED` instead of stuck `INITIALIZING` forever, so later
+    /// callers panic loudly instead of reading uninitialized memory.
+    ///
+    /// Takes `&self` rather than `&'static self`: unlike `get()`, this never hands out a
+    /// reference tied to the cell's lifetime, so it doesn't need the longer borrow. This lets
+    /// [`LazyStaticCell`] reuse it without requiring its own `&self` to be `&'static`.
+    fn try_init(&self, f: impl FnOnce() -> T) -> bool {
+        if self
+            .state
+            .compare_exchange(
+                ONCE_EMPTY,
+                ONCE_INITIALIZING,
+                Ordering::Relaxed,
+                Ordering::Relaxed,
+            )
+            .is_err()
+        {
+            return false;
+        }
+
+        // If `f()` panics, this guard's `Drop` runs during unwinding and leaves the cell
+        // `POISONED` instead of stuck `INITIALIZING` forever.
+        let guard = PoisonOnUnwind {
+            state: &self.state,
+            poisoned: ONCE_POISONED,
+        };
+        let val = f();
+        guard.defuse();
+
+        // SAFETY: The `compare_exchange` above is the only way to reach `INITIALIZING`, and only
+        // one caller can win it, so we're the only one touching `val`.
+        unsafe { (*self.val.get()).write(val) };
+        self.state.store(ONCE_SET, Ordering::Release);
+        true
+    }
+
+    /// Set the value of the `OnceStaticCell`.
+    ///
+    /// If the cell was already set, this returns `val` back in `Err`, leaving the cell untouched.
+    #[inline]
+    pub fn set(&'static self, val: T) -> Result<(), T> {
+        let mut val = Some(val);
+        if self.try_init(|| val.take().unwrap()) {
+            Ok(())
+        } else {
+            Err(val.unwrap())
+        }
+    }
+
+    /// Get a reference to the contained value, if it has been set.
+    ///
+    /// # Panics
+    ///
+    /// Panics if a previous [`OnceStaticCell::get_or_init()`] call's closure panicked.
+    #[inline]
+    pub fn get(&'static self) -> Option<&'static T> {
+        self.get_ref()
+    }
+
+    /// Like [`OnceStaticCell::get()`], but takes `&self` and returns a reference tied to that
+    /// borrow instead of `&'static T`. Shared with [`LazyStaticCell`], which can't assume its
+    /// own `&self` is `&'static`.
+    fn get_ref(&self) -> Option<&T> {
+        match self.state.load(Ordering::Acquire) {
+            ONCE_SET => {
+                // SAFETY: The value has been set, and it stays set forever, so it's sound to
+                // hand out a shared reference to it for as long as the `OnceStaticCell` lives.
+                Some(unsafe { (*self.val.get()).assume_init_ref() })
+            }
+            ONCE_POISONED => panic!("OnceStaticCell: initialization function panicked"),
+            _ => None,
+        }
+    }
+
+    /// Get a reference to the contained value, initializing it with `f` if it's not set yet.
+    ///
+    /// If this races with another call to `set()` or `get_or_init()`, exactly one of them runs
+    /// its closure; this one returns the value that ended up stored, whether or not it was the
+    /// one that initialized it.
+    ///
+    /// This lives on `OnceStaticCell` rather than `StaticCell` because `StaticCell` hands out a
+    /// single exclusive `&'static mut T`: a "get or init" that can return a shared reference to
+    /// racing losers has no sound equivalent there. `OnceStaticCell` already hands out shared
+    /// `&'static T`s, so it's the type that can actually support this.
+    ///
+    /// Without the `critical-section` feature, a racing caller that loses the init race spins
+    /// until the winner's write becomes visible; this is correct but can busy-loop briefly on
+    /// targets with weak memory ordering. Enable the `critical-section` feature to instead run
+    /// the whole check-and-init inside a global critical section, which closes that window at
+    /// the cost of briefly disabling interrupts.
+    #[cfg(not(feature = "critical-section"))]
+    #[inline]
+    pub fn get_or_init(&'static self, f: impl FnOnce() -> T) -> &'static T {
+        self.try_init(f);
+        // Spin until the winner's write (which may be in progress on another context) is visible.
+        loop {
+            if let Some(val) = self.get() {
+                return val;
+            }
+            core::hint::spin_loop();
+        }
+    }
+
+    /// Get a reference to the contained value, initializing it with `f` if it's not set yet.
+    ///
+    /// The whole check-and-init runs inside a [`critical_section::with()`] critical section, so
+    /// the first caller fully constructs `T` before any racing caller can observe the cell, with
+    /// no busy-waiting window. See [`OnceStaticCell::get_or_init()`] for the non-`critical-section` version.
+    #[cfg(feature = "critical-section")]
+    #[cfg_attr(docsrs, doc(cfg(feature = "critical-section")))]
+    #[inline]
+    pub fn get_or_init(&'static self, f: impl FnOnce() -> T) -> &'static T {
+        critical_section::with(|_cs| {
+            self.try_init(f);
+        });
+        // SAFETY: the critical section above guarantees the cell is set by the time it returns.
+        self.get().unwrap()
+    }
+}
+
+impl<T> Default for OnceStaticCell<T> {
+    #[inline]
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+// ---
+
+/// Statically allocated cell that lazily initializes itself with a closure on first access.
+///
+/// It has no `new`-time cost: the closure `f` is stored, untouched, until the first time the
+/// cell is dereferenced, at which point it runs exactly once to produce the `T` that every
+/// subsequent dereference returns.
+///
+/// `LazyStaticCell` is `Sync` and safe to use from interrupt handlers and other cores: if
+/// several contexts dereference it at the same time, exactly one of them runs `f`, and the
+/// others spin until that run has stored its result. If `f` panics, the cell is left
+/// "poisoned" and every later access panics too, rather than risking a read of uninitialized
+/// memory.
+///
+/// Internally, this is just an [`OnceStaticCell`] plus the stashed-away init closure: `init()`
+/// calls the same `try_init`/`get_ref` machinery `OnceStaticCell` uses for `set()`/`get()`, so
+/// the two types share one implementation of the "empty/initializing/set/poisoned" state
+/// machine rather than keeping independent copies in sync by hand.
+///
+/// See the [crate-level docs](crate) for usage.
+pub struct LazyStaticCell<T, F = fn() -> T> {
+    once: OnceStaticCell<T>,
+    f: UnsafeCell<Option<F>>,
+}
+
+unsafe impl<T: Send, F: Send> Send for LazyStaticCell<T, F> {}
+unsafe impl<T: Send + Sync, F: Send> Sync for LazyStaticCell<T, F> {}
+
+impl<T, F> LazyStaticCell<T, F> {
+    /// Create a new `LazyStaticCell`, which will initialize itself with `f` on first access.
+    #[inline]
+    pub const fn new(f: F) -> Self {
+        Self {
+            once: OnceStaticCell::new(),
+            f: UnsafeCell::new(Some(f)),
+        }
+    }
+}
+
+impl<T, F: FnOnce() -> T> LazyStaticCell<T, F> {
+    /// Ensure the value is initialized, blocking until it is if another context is mid-init.
+    fn init(&self) {
+        if self.once.get_ref().is_some() {
+            return;
+        }
+        self.once.try_init(|| {
+            // SAFETY: `try_init` only runs its closure for the one caller that wins the
+            // `EMPTY` -> `INITIALIZING` race, so we're the only one touching `f`.
+            let f = unsafe { (*self.f.get()).take() }
+                .expect("LazyStaticCell state machine invariant violated");
+            f()
+        });
+        // If we lost the race, `try_init` returned without running `f`; spin until the winner's
+        // write becomes visible, same as `OnceStaticCell::get_or_init()` does.
+        while self.once.get_ref().is_none() {
+            core::hint::spin_loop();
+        }
+    }
+}
+
+impl<T, F: FnOnce() -> T> Deref for LazyStaticCell<T, F> {
+    type Target = T;
+
+    #[inline]
+    fn deref(&self) -> &T {
+        self.init();
+        // SAFETY: `init()` only returns once `get_ref()` has observed the value set (the
+        // poisoned case always panics), so the value is guaranteed to have been written.
+        self.once.get_ref().unwrap()
+    }
 }
 
 /// Convert a `T` to a `&'static mut T`.
@@ -246,7 +574,7 @@ macro_rules! make_static {
 
 #[cfg(test)]
 mod tests {
-    use crate::StaticCell;
+    use crate::{ConstStaticCell, LazyStaticCell, OnceStaticCell, StaticCell};
 
     #[test]
     fn test_static_cell() {
@@ -255,6 +583,57 @@ mod tests {
         assert_eq!(*val, 42);
     }
 
+    #[test]
+    fn test_static_cell_reset() {
+        static CELL: StaticCell<u32> = StaticCell::new();
+        let val = CELL.init(42u32);
+        assert_eq!(*val, 42);
+        assert_eq!(CELL.reset(val), 42);
+        let val = CELL.init(43u32);
+        assert_eq!(*val, 43);
+    }
+
+    #[test]
+    fn test_const_static_cell_untake() {
+        static CELL: ConstStaticCell<u32> = ConstStaticCell::new(42);
+        let val = CELL.take();
+        assert_eq!(*val, 42);
+        *val = 43;
+        CELL.untake(val);
+        let val = CELL.take();
+        assert_eq!(*val, 43);
+    }
+
+    #[test]
+    fn test_once_static_cell() {
+        static CELL: OnceStaticCell<u32> = OnceStaticCell::new();
+        assert_eq!(CELL.get(), None);
+        assert_eq!(CELL.set(42), Ok(()));
+        assert_eq!(CELL.set(43), Err(43));
+        assert_eq!(CELL.get(), Some(&42));
+        assert_eq!(*CELL.get_or_init(|| 43), 42);
+    }
+
+    #[test]
+    fn test_once_static_cell_poison() {
+        static CELL: OnceStaticCell<u32> = OnceStaticCell::new();
+        let panicked = std::panic::catch_unwind(|| {
+            CELL.get_or_init(|| panic!("boom"));
+        });
+        assert!(panicked.is_err());
+
+        // The cell is now poisoned: later accesses panic instead of reading uninitialized memory.
+        assert!(std::panic::catch_unwind(|| CELL.get()).is_err());
+        assert!(std::panic::catch_unwind(|| CELL.get_or_init(|| 1)).is_err());
+    }
+
+    #[test]
+    fn test_lazy_static_cell() {
+        static CELL: LazyStaticCell<u32> = LazyStaticCell::new(|| 42);
+        assert_eq!(*CELL, 42);
+        assert_eq!(*CELL, 42);
+    }
+
     #[cfg(feature = "nightly")]
     #[test]
     fn test_make_static() {